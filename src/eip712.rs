@@ -0,0 +1,248 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of Open Ethereum.
+
+// Open Ethereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Open Ethereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Open Ethereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! EIP-712 structured-data hashing.
+//!
+//! This lets `SignedTransaction`-style sender recovery be reused for
+//! off-chain typed messages: a message is `{ types, primary_type, domain,
+//! message }`, and [`TypedData::signing_hash`] computes the 32-byte digest
+//! that gets signed, following the algorithm in the EIP:
+//!
+//! - `encodeType(T)` is `T(name₁ type₁,…)` followed by the sorted, unique,
+//!   referenced struct types.
+//! - `typeHash(T) = keccak256(encodeType(T))`.
+//! - `encodeData(T)` is `typeHash(T) || enc(field₁) || …`, where atomics are
+//!   one 32-byte word (integers, `bool` and `address` left-padded, `bytesN`
+//!   right-padded), dynamic `bytes`/`string` are `keccak256(bytes)`, and
+//!   structs/arrays recurse through `hashStruct`.
+//! - `hashStruct(T, data) = keccak256(encodeData(T, data))`.
+//! - the digest is `keccak256(0x19 || 0x01 || hashStruct(domain) ||
+//!   hashStruct(primaryType, message))`.
+
+use std::collections::{HashMap, HashSet};
+
+use ethereum_types::{Address, H256, U256};
+use hash::keccak;
+use parity_crypto::publickey::{self, Public, Signature, recover, public_to_address};
+use serde_json::Value;
+
+/// One field of a struct type, as named in the `types` section of a typed
+/// message (e.g. `{ "name": "wallet", "type": "address" }`).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Eip712Field {
+	/// Field name.
+	pub name: String,
+	/// Field type: an atomic type (`address`, `bool`, `uint256`, …), a
+	/// dynamic type (`bytes`, `string`), a reference to another struct
+	/// type, or any of those suffixed with `[]` for an array.
+	#[serde(rename = "type")]
+	pub kind: String,
+}
+
+/// An EIP-712 typed message: the struct-type declarations it's built from,
+/// which one is being signed, the domain separator fields, and the message
+/// data itself.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TypedData {
+	/// Struct type declarations referenced by `domain` and `message`.
+	pub types: HashMap<String, Vec<Eip712Field>>,
+	/// The struct type of `message`, naming an entry in `types`.
+	#[serde(rename = "primaryType")]
+	pub primary_type: String,
+	/// Domain separator fields; always hashed as the `EIP712Domain` type.
+	pub domain: Value,
+	/// The message being signed, shaped as `primary_type`.
+	pub message: Value,
+}
+
+/// Errors that can occur while hashing or recovering a typed message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+	/// Referenced a struct type that has no entry in `types`.
+	UnknownType(String),
+	/// A field was missing from the supplied data.
+	MissingField(String),
+	/// A field's value didn't match the shape its declared type expects.
+	InvalidValue {
+		/// The offending field's declared type.
+		kind: String,
+		/// The offending value, rendered for diagnostics.
+		value: String,
+	},
+}
+
+impl ::std::fmt::Display for Error {
+	fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+		match *self {
+			Error::UnknownType(ref name) => write!(f, "unknown EIP-712 type: {}", name),
+			Error::MissingField(ref name) => write!(f, "missing EIP-712 field: {}", name),
+			Error::InvalidValue { ref kind, ref value } => write!(f, "value {} is not a valid {}", value, kind),
+		}
+	}
+}
+
+impl ::std::error::Error for Error {}
+
+impl TypedData {
+	/// The EIP-712 signing digest for this message:
+	/// `keccak256(0x19 || 0x01 || hashStruct(domain) || hashStruct(primaryType, message))`.
+	pub fn signing_hash(&self) -> Result<H256, Error> {
+		let domain_hash = self.hash_struct("EIP712Domain", &self.domain)?;
+		let message_hash = self.hash_struct(&self.primary_type, &self.message)?;
+
+		let mut buf = Vec::with_capacity(2 + 32 + 32);
+		buf.extend_from_slice(&[0x19, 0x01]);
+		buf.extend_from_slice(domain_hash.as_bytes());
+		buf.extend_from_slice(message_hash.as_bytes());
+		Ok(keccak(buf))
+	}
+
+	/// Recover the address that produced `signature` over this message's
+	/// signing hash.
+	pub fn recover(&self, signature: &Signature) -> Result<Address, publickey::Error> {
+		let hash = self.signing_hash().map_err(|_| publickey::Error::InvalidSignature)?;
+		let public: Public = recover(signature, &hash)?;
+		Ok(public_to_address(&public))
+	}
+
+	/// `typeHash(T) = keccak256(encodeType(T))`.
+	fn type_hash(&self, primary_type: &str) -> Result<H256, Error> {
+		Ok(keccak(self.encode_type(primary_type)?))
+	}
+
+	/// `encodeType(T)`: `T(name₁ type₁,…)` followed by the sorted, unique,
+	/// struct types it (transitively) references.
+	fn encode_type(&self, primary_type: &str) -> Result<String, Error> {
+		let mut deps = HashSet::new();
+		self.collect_dependencies(primary_type, &mut deps)?;
+		deps.remove(primary_type);
+
+		let mut deps: Vec<&str> = deps.iter().map(String::as_str).collect();
+		deps.sort();
+
+		let mut encoded = self.encode_struct_type(primary_type)?;
+		for dep in deps {
+			encoded.push_str(&self.encode_struct_type(dep)?);
+		}
+		Ok(encoded)
+	}
+
+	fn encode_struct_type(&self, name: &str) -> Result<String, Error> {
+		let fields = self.types.get(name).ok_or_else(|| Error::UnknownType(name.to_string()))?;
+		let fields = fields.iter().map(|f| format!("{} {}", f.kind, f.name)).collect::<Vec<_>>().join(",");
+		Ok(format!("{}({})", name, fields))
+	}
+
+	fn collect_dependencies(&self, name: &str, deps: &mut HashSet<String>) -> Result<(), Error> {
+		if deps.contains(name) {
+			return Ok(());
+		}
+		let fields = match self.types.get(name) {
+			Some(fields) => fields,
+			// Referenced type has no declaration of its own: it's an
+			// atomic or dynamic type, not a struct, and has no
+			// dependencies to collect.
+			None => return Ok(()),
+		};
+		deps.insert(name.to_string());
+		for field in fields {
+			self.collect_dependencies(strip_array_suffix(&field.kind), deps)?;
+		}
+		Ok(())
+	}
+
+	/// `hashStruct(T, data) = keccak256(encodeData(T, data))`.
+	fn hash_struct(&self, primary_type: &str, data: &Value) -> Result<H256, Error> {
+		Ok(keccak(self.encode_data(primary_type, data)?))
+	}
+
+	/// `encodeData(T)` is `typeHash(T) || enc(field₁) || …`.
+	fn encode_data(&self, primary_type: &str, data: &Value) -> Result<Vec<u8>, Error> {
+		let fields = self.types.get(primary_type).ok_or_else(|| Error::UnknownType(primary_type.to_string()))?;
+
+		let mut encoded = self.type_hash(primary_type)?.as_bytes().to_vec();
+		for field in fields {
+			let value = data.get(&field.name).ok_or_else(|| Error::MissingField(field.name.clone()))?;
+			encoded.extend_from_slice(self.encode_value(&field.kind, value)?.as_bytes());
+		}
+		Ok(encoded)
+	}
+
+	/// Encode a single field's value to its atomic 32-byte word, or to
+	/// `keccak256` of its contents for a dynamic/struct/array type.
+	fn encode_value(&self, kind: &str, value: &Value) -> Result<H256, Error> {
+		if let Some(element_type) = kind.strip_suffix("[]") {
+			let items = value.as_array().ok_or_else(|| invalid(kind, value))?;
+			let mut encoded = Vec::with_capacity(items.len() * 32);
+			for item in items {
+				encoded.extend_from_slice(self.encode_value(element_type, item)?.as_bytes());
+			}
+			return Ok(keccak(encoded));
+		}
+
+		if self.types.contains_key(kind) {
+			return self.hash_struct(kind, value);
+		}
+
+		match kind {
+			"string" => Ok(keccak(value.as_str().ok_or_else(|| invalid(kind, value))?.as_bytes())),
+			"bytes" => Ok(keccak(decode_bytes(value).ok_or_else(|| invalid(kind, value))?)),
+			"address" => {
+				let address = value.as_str()
+					.and_then(|s| s.trim_start_matches("0x").parse::<Address>().ok())
+					.ok_or_else(|| invalid(kind, value))?;
+				Ok(H256::from(address))
+			}
+			"bool" => {
+				let b = value.as_bool().ok_or_else(|| invalid(kind, value))?;
+				Ok(H256::from_low_u64_be(b as u64))
+			}
+			_ if kind.starts_with("uint") || kind.starts_with("int") => {
+				let n = parse_uint(value).ok_or_else(|| invalid(kind, value))?;
+				Ok(H256::from(n))
+			}
+			_ if kind.starts_with("bytes") => {
+				let mut bytes = decode_bytes(value).ok_or_else(|| invalid(kind, value))?;
+				bytes.resize(32, 0);
+				Ok(H256::from_slice(&bytes))
+			}
+			_ => Err(Error::UnknownType(kind.to_string())),
+		}
+	}
+}
+
+fn invalid(kind: &str, value: &Value) -> Error {
+	Error::InvalidValue { kind: kind.to_string(), value: value.to_string() }
+}
+
+fn strip_array_suffix(kind: &str) -> &str {
+	kind.strip_suffix("[]").unwrap_or(kind)
+}
+
+fn decode_bytes(value: &Value) -> Option<Vec<u8>> {
+	use rustc_hex::FromHex;
+	value.as_str()?.trim_start_matches("0x").from_hex::<Vec<u8>>().ok()
+}
+
+fn parse_uint(value: &Value) -> Option<U256> {
+	if let Some(s) = value.as_str() {
+		if let Some(hex) = s.strip_prefix("0x") {
+			return U256::from_str_radix(hex, 16).ok();
+		}
+		return U256::from_dec_str(s).ok();
+	}
+	value.as_u64().map(U256::from)
+}
@@ -0,0 +1,77 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of Open Ethereum.
+
+// Open Ethereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Open Ethereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Open Ethereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! EIP-2930 access lists.
+//!
+//! An access list names, up front, the addresses and storage keys a
+//! transaction expects to touch. It's defined here rather than next to any
+//! one transaction type because this is the shared-types crate: the miner,
+//! verification, and tracing crates can all reference this one definition
+//! instead of each rolling their own.
+
+use ethereum_types::{Address, H256};
+use rlp::{Rlp, RlpStream, Encodable, Decodable, DecoderError};
+
+/// One entry of an [`AccessList`]: an address paired with the storage keys
+/// within it that the transaction expects to touch.
+#[derive(Debug, Clone, PartialEq, Eq, Default, RlpEncodable, RlpDecodable)]
+pub struct AccessListItem {
+	/// The address being accessed.
+	pub address: Address,
+	/// Storage keys within `address` that are accessed.
+	pub storage_keys: Vec<H256>,
+}
+
+/// An EIP-2930 access list: a list of addresses and the storage keys within
+/// them that a transaction expects to touch.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AccessList(pub Vec<AccessListItem>);
+
+impl AccessList {
+	/// The empty access list.
+	pub fn new() -> Self { AccessList(Vec::new()) }
+
+	/// Iterate over the entries, each an [`AccessListItem`] holding an
+	/// address and its storage keys.
+	pub fn iter(&self) -> impl Iterator<Item = &AccessListItem> { self.0.iter() }
+
+	/// Number of addresses named in this access list.
+	pub fn len(&self) -> usize { self.0.len() }
+
+	/// Whether this access list names no addresses.
+	pub fn is_empty(&self) -> bool { self.0.is_empty() }
+}
+
+impl From<Vec<(Address, Vec<H256>)>> for AccessList {
+	fn from(entries: Vec<(Address, Vec<H256>)>) -> Self {
+		AccessList(entries.into_iter().map(|(address, storage_keys)| AccessListItem { address, storage_keys }).collect())
+	}
+}
+
+impl Encodable for AccessList {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		s.begin_list(self.0.len());
+		for item in &self.0 {
+			item.rlp_append(s);
+		}
+	}
+}
+
+impl Decodable for AccessList {
+	fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+		Ok(AccessList(rlp.iter().map(|item| AccessListItem::decode(&item)).collect::<Result<Vec<_>, _>>()?))
+	}
+}
@@ -39,6 +39,28 @@ pub enum Error {
 		/// Transaction gas price
 		got: U256,
 	},
+	/// Legacy transaction's gas price is below the block's base fee; only
+	/// possible on a chain that has activated EIP-1559.
+	GasPriceLowerThanBaseFee {
+		/// Transaction gas price
+		got: U256,
+		/// Block base fee
+		base: U256,
+	},
+	/// Fee-market (EIP-1559) transaction's priority fee is higher than its fee cap.
+	MaxPriorityFeeGreaterThanMaxFee {
+		/// Transaction priority fee
+		priority: U256,
+		/// Transaction fee cap
+		max: U256,
+	},
+	/// Fee-market (EIP-1559) transaction's fee cap cannot cover the block's base fee.
+	MaxFeePerGasLowerThanBaseFee {
+		/// Transaction fee cap
+		max: U256,
+		/// Block base fee
+		base: U256,
+	},
 	/// Transaction has too low fee
 	/// (there is already a transaction with the same sender-nonce but higher gas price)
 	TooCheapToReplace {
@@ -113,6 +135,12 @@ impl fmt::Display for Error {
 			LimitReached => "Transaction limit reached".into(),
 			InsufficientGasPrice { minimal, got } =>
 				format!("Insufficient gas price. Min={}, Given={}", minimal, got),
+			GasPriceLowerThanBaseFee { got, base } =>
+				format!("Gas price is lower than the block's base fee. Base={}, Given={}", base, got),
+			MaxPriorityFeeGreaterThanMaxFee { priority, max } =>
+				format!("Priority fee is greater than the max fee. Priority={}, Max={}", priority, max),
+			MaxFeePerGasLowerThanBaseFee { max, base } =>
+				format!("Max fee per gas is lower than the block's base fee. Base={}, Max={}", base, max),
 			InsufficientGas { minimal, got } =>
 				format!("Insufficient gas. Min={}, Given={}", minimal, got),
 			InsufficientBalance { balance, cost } =>
@@ -0,0 +1,640 @@
+// Copyright 2015-2020 Parity Technologies (UK) Ltd.
+// This file is part of Open Ethereum.
+
+// Open Ethereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Open Ethereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Open Ethereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Transaction data structure.
+//!
+//! A transaction is either a legacy RLP-list transaction or, since EIP-2718,
+//! a "typed" transaction: a single byte identifying the transaction type
+//! followed by a type-specific RLP payload. This module decodes both forms
+//! behind a single `TypedTransaction` envelope so that the rest of the
+//! codebase does not need to know which flavour of transaction it is
+//! holding.
+
+pub mod error;
+mod access_list;
+
+pub use self::error::Error;
+pub use self::access_list::{AccessList, AccessListItem};
+
+use std::ops::Deref;
+
+use ethereum_types::{H256, U256, Address};
+use hash::keccak;
+use parity_crypto::publickey::{Signature, Public, Secret, recover, sign, public_to_address};
+use rlp::{self, Rlp, RlpStream, Encodable, Decodable, DecoderError};
+
+/// Fake address for unsigned transactions, as defined by EIP-86.
+pub const UNSIGNED_SENDER: Address = Address::zero();
+
+/// Transaction action type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Action {
+	/// Create creates new contract.
+	Create,
+	/// Calls contract at given address.
+	/// In the case of a transfer, this is the receiver's address.
+	Call(Address),
+}
+
+impl Default for Action {
+	fn default() -> Action { Action::Create }
+}
+
+impl rlp::Decodable for Action {
+	fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+		if rlp.is_empty() {
+			Ok(Action::Create)
+		} else {
+			Ok(Action::Call(rlp.as_val()?))
+		}
+	}
+}
+
+impl rlp::Encodable for Action {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		match *self {
+			Action::Create => s.append_internal(&""),
+			Action::Call(ref address) => s.append_internal(address),
+		}
+	}
+}
+
+/// A set of information describing an externally-originating message call
+/// or contract creation, as understood by the legacy (pre-EIP-2718) wire
+/// format.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Transaction {
+	/// Nonce.
+	pub nonce: U256,
+	/// Gas price.
+	pub gas_price: U256,
+	/// Gas paid up front for transaction execution.
+	pub gas: U256,
+	/// Action, can be either call or contract create.
+	pub action: Action,
+	/// Transferred value.
+	pub value: U256,
+	/// Transaction data.
+	pub data: Vec<u8>,
+}
+
+impl Transaction {
+	/// Append the fields of this transaction, without the signature, to the
+	/// given stream. When `chain_id` is supplied the EIP-155 replay
+	/// protection fields are included.
+	fn rlp_append_unsigned(&self, s: &mut RlpStream, chain_id: Option<u64>) {
+		match chain_id {
+			None => { s.begin_list(6); }
+			Some(_) => { s.begin_list(9); }
+		}
+		s.append(&self.nonce);
+		s.append(&self.gas_price);
+		s.append(&self.gas);
+		s.append(&self.action);
+		s.append(&self.value);
+		s.append(&self.data);
+		if let Some(chain_id) = chain_id {
+			s.append(&chain_id);
+			s.append(&0u8);
+			s.append(&0u8);
+		}
+	}
+}
+
+/// An EIP-2930 (type `0x01`) access-list transaction: a legacy-priced
+/// transaction that additionally declares up front the addresses and
+/// storage keys it expects to touch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessListTransaction {
+	/// Chain ID this transaction is valid on; unlike a legacy transaction
+	/// this is carried explicitly rather than folded into `v`.
+	pub chain_id: u64,
+	/// Nonce.
+	pub nonce: U256,
+	/// Gas price.
+	pub gas_price: U256,
+	/// Gas paid up front for transaction execution.
+	pub gas: U256,
+	/// Action, can be either call or contract create.
+	pub action: Action,
+	/// Transferred value.
+	pub value: U256,
+	/// Transaction data.
+	pub data: Vec<u8>,
+	/// Addresses and storage keys the transaction expects to touch.
+	pub access_list: AccessList,
+}
+
+impl AccessListTransaction {
+	fn rlp_append_unsigned(&self, s: &mut RlpStream) {
+		s.begin_list(8);
+		s.append(&self.chain_id);
+		s.append(&self.nonce);
+		s.append(&self.gas_price);
+		s.append(&self.gas);
+		s.append(&self.action);
+		s.append(&self.value);
+		s.append(&self.data);
+		self.access_list.rlp_append(s);
+	}
+}
+
+/// An EIP-1559 (type `0x02`) fee-market transaction: the sender names a
+/// `max_fee_per_gas` they are willing to pay in total and a
+/// `max_priority_fee_per_gas` tip to the block producer, and the network
+/// settles the actual price against the block's base fee.
+///
+/// This is a standalone struct rather than extra fields bolted onto
+/// `Transaction`, the same way `AccessListTransaction` and `Transaction`
+/// don't share a struct: a fee cap plus priority fee is a different fee
+/// model from the flat `gas_price` of the other two types, and folding
+/// them into one struct would mean every consumer has to know which fields
+/// are meaningless for which `TypedTransaction` variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip1559Transaction {
+	/// Chain ID this transaction is valid on; unlike a legacy transaction
+	/// this is carried explicitly rather than folded into `v`.
+	pub chain_id: u64,
+	/// Nonce.
+	pub nonce: U256,
+	/// Maximum tip per unit of gas paid to the block producer.
+	pub max_priority_fee_per_gas: U256,
+	/// Maximum total price per unit of gas the sender is willing to pay.
+	pub max_fee_per_gas: U256,
+	/// Gas paid up front for transaction execution.
+	pub gas: U256,
+	/// Action, can be either call or contract create.
+	pub action: Action,
+	/// Transferred value.
+	pub value: U256,
+	/// Transaction data.
+	pub data: Vec<u8>,
+	/// Addresses and storage keys the transaction expects to touch.
+	pub access_list: AccessList,
+}
+
+impl Eip1559Transaction {
+	/// Returns `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)`,
+	/// the price per unit of gas this transaction actually pays when
+	/// included in a block with the given base fee.
+	pub fn effective_gas_price(&self, base_fee: U256) -> U256 {
+		base_fee.saturating_add(self.max_priority_fee_per_gas).min(self.max_fee_per_gas)
+	}
+
+	fn rlp_append_unsigned(&self, s: &mut RlpStream) {
+		s.begin_list(9);
+		s.append(&self.chain_id);
+		s.append(&self.nonce);
+		s.append(&self.max_priority_fee_per_gas);
+		s.append(&self.max_fee_per_gas);
+		s.append(&self.gas);
+		s.append(&self.action);
+		s.append(&self.value);
+		s.append(&self.data);
+		self.access_list.rlp_append(s);
+	}
+}
+
+/// The type-specific, signature-less payload carried by a transaction.
+///
+/// Every envelope variant wraps the fields required by the EIP introducing
+/// the type. Adding a post-Berlin transaction format means adding a
+/// variant here.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypedTransaction {
+	/// Pre-EIP-2718 RLP-list transaction; no envelope type byte.
+	Legacy(Transaction),
+	/// EIP-2930 (type `0x01`) access-list transaction.
+	AccessList(AccessListTransaction),
+	/// EIP-1559 (type `0x02`) fee-market transaction.
+	Eip1559(Eip1559Transaction),
+}
+
+impl TypedTransaction {
+	/// The EIP-2718 type identifier for this transaction, or `None` for a
+	/// legacy transaction (which has no envelope byte).
+	pub fn tx_type(&self) -> Option<u8> {
+		match *self {
+			TypedTransaction::Legacy(_) => None,
+			TypedTransaction::AccessList(_) => Some(0x01),
+			TypedTransaction::Eip1559(_) => Some(0x02),
+		}
+	}
+
+	/// Nonce.
+	pub fn nonce(&self) -> U256 {
+		match *self {
+			TypedTransaction::Legacy(ref tx) => tx.nonce,
+			TypedTransaction::AccessList(ref tx) => tx.nonce,
+			TypedTransaction::Eip1559(ref tx) => tx.nonce,
+		}
+	}
+
+	/// Gas paid up front for transaction execution.
+	pub fn gas(&self) -> U256 {
+		match *self {
+			TypedTransaction::Legacy(ref tx) => tx.gas,
+			TypedTransaction::AccessList(ref tx) => tx.gas,
+			TypedTransaction::Eip1559(ref tx) => tx.gas,
+		}
+	}
+
+	/// Action, can be either call or contract create.
+	pub fn action(&self) -> &Action {
+		match *self {
+			TypedTransaction::Legacy(ref tx) => &tx.action,
+			TypedTransaction::AccessList(ref tx) => &tx.action,
+			TypedTransaction::Eip1559(ref tx) => &tx.action,
+		}
+	}
+
+	/// Transferred value.
+	pub fn value(&self) -> U256 {
+		match *self {
+			TypedTransaction::Legacy(ref tx) => tx.value,
+			TypedTransaction::AccessList(ref tx) => tx.value,
+			TypedTransaction::Eip1559(ref tx) => tx.value,
+		}
+	}
+
+	/// Transaction data.
+	pub fn data(&self) -> &[u8] {
+		match *self {
+			TypedTransaction::Legacy(ref tx) => &tx.data,
+			TypedTransaction::AccessList(ref tx) => &tx.data,
+			TypedTransaction::Eip1559(ref tx) => &tx.data,
+		}
+	}
+
+	/// The access list declared by this transaction; `None` for a legacy
+	/// transaction, which predates EIP-2930.
+	pub fn access_list(&self) -> Option<&AccessList> {
+		match *self {
+			TypedTransaction::Legacy(_) => None,
+			TypedTransaction::AccessList(ref tx) => Some(&tx.access_list),
+			TypedTransaction::Eip1559(ref tx) => Some(&tx.access_list),
+		}
+	}
+
+	/// The price per unit of gas this transaction is willing to pay, in
+	/// whichever representation its type uses: the flat `gas_price` for a
+	/// legacy or access-list transaction, or the `max_fee_per_gas` cap for
+	/// a fee-market one. Use [`TypedTransaction::effective_gas_price`] to
+	/// get the price actually paid once a block's base fee is known.
+	pub fn gas_price(&self) -> U256 {
+		match *self {
+			TypedTransaction::Legacy(ref tx) => tx.gas_price,
+			TypedTransaction::AccessList(ref tx) => tx.gas_price,
+			TypedTransaction::Eip1559(ref tx) => tx.max_fee_per_gas,
+		}
+	}
+
+	/// The price per unit of gas this transaction actually pays when
+	/// included in a block with the given base fee.
+	pub fn effective_gas_price(&self, base_fee: U256) -> U256 {
+		match *self {
+			TypedTransaction::Legacy(ref tx) => tx.gas_price,
+			TypedTransaction::AccessList(ref tx) => tx.gas_price,
+			TypedTransaction::Eip1559(ref tx) => tx.effective_gas_price(base_fee),
+		}
+	}
+
+	/// RLP-encode the signature-less payload for this transaction type.
+	fn rlp_append_unsigned(&self, s: &mut RlpStream, chain_id: Option<u64>) {
+		match *self {
+			TypedTransaction::Legacy(ref tx) => tx.rlp_append_unsigned(s, chain_id),
+			TypedTransaction::AccessList(ref tx) => tx.rlp_append_unsigned(s),
+			TypedTransaction::Eip1559(ref tx) => tx.rlp_append_unsigned(s),
+		}
+	}
+
+	/// The bytes that get hashed to produce the signing hash: for a typed
+	/// transaction this is `rlp(payload)`, to be prefixed with the type
+	/// byte by the caller; for a legacy transaction it's the whole of what
+	/// gets hashed.
+	fn encode_unsigned(&self, chain_id: Option<u64>) -> Vec<u8> {
+		let mut s = RlpStream::new();
+		self.rlp_append_unsigned(&mut s, chain_id);
+		s.out()
+	}
+
+	/// The hash that gets signed by the sender:
+	/// `keccak256(rlp(...))` for a legacy transaction, or
+	/// `keccak256(type_byte || rlp(payload))` for a typed one.
+	pub fn signing_hash(&self, chain_id: Option<u64>) -> H256 {
+		match self.tx_type() {
+			None => keccak(self.encode_unsigned(chain_id)),
+			Some(type_id) => {
+				let mut buf = vec![type_id];
+				buf.extend_from_slice(&self.encode_unsigned(chain_id));
+				keccak(buf)
+			}
+		}
+	}
+
+	/// Sign this transaction with `secret`, producing a fully-signed (but
+	/// not yet sender-verified) transaction. `chain_id` is only consulted
+	/// for legacy transactions, which fold it into `v` per EIP-155; typed
+	/// transactions carry their chain ID as a field instead.
+	pub fn sign(self, secret: &Secret, chain_id: Option<u64>) -> UnverifiedTransaction {
+		let signing_hash = self.signing_hash(chain_id);
+		let sig = sign(secret, &signing_hash).expect("secret is valid so signing cannot fail; qed");
+		let r = U256::from_big_endian(sig.r());
+		let s = U256::from_big_endian(sig.s());
+		let v = match self.tx_type() {
+			None => sig.v() as u64 + chain_id.map_or(27, |id| 35 + id * 2),
+			Some(_) => sig.v() as u64, // typed transactions carry the raw y_parity
+		};
+		UnverifiedTransaction::new(self, v, r, s)
+	}
+}
+
+/// Signed transaction information without verified signer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UnverifiedTransaction {
+	/// Plain transaction, not including the type byte or the signature.
+	unsigned: TypedTransaction,
+	/// The `v` component of the signature. For a legacy transaction this is
+	/// EIP-155-encoded (`chain_id * 2 + 35 + recovery_id`); for a typed
+	/// transaction it is the raw `y_parity` (`0` or `1`).
+	v: u64,
+	/// The `r` component of the signature.
+	r: U256,
+	/// The `s` component of the signature.
+	s: U256,
+	/// Cached hash of the whole envelope (type byte, if any, plus the
+	/// signed RLP payload).
+	hash: H256,
+}
+
+impl Deref for UnverifiedTransaction {
+	type Target = TypedTransaction;
+	fn deref(&self) -> &TypedTransaction { &self.unsigned }
+}
+
+impl rlp::Encodable for UnverifiedTransaction {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		self.rlp_append_envelope(s);
+	}
+}
+
+impl UnverifiedTransaction {
+	/// Build an `UnverifiedTransaction` from its already-computed signature
+	/// components, deriving the envelope hash from them. Used by
+	/// [`TypedTransaction::sign`]; decoding existing bytes should go
+	/// through [`UnverifiedTransaction::decode`] instead.
+	fn new(unsigned: TypedTransaction, v: u64, r: U256, s: U256) -> Self {
+		let mut tx = UnverifiedTransaction { unsigned, v, r, s, hash: H256::zero() };
+		tx.hash = keccak(tx.encode());
+		tx
+	}
+
+	/// Full wire-format encoding of this transaction: the EIP-2718 type
+	/// byte followed by the RLP payload for a typed transaction, or just
+	/// the RLP list for a legacy one.
+	pub fn encode(&self) -> Vec<u8> {
+		let rlp = rlp::encode(self);
+		match self.unsigned.tx_type() {
+			None => rlp,
+			Some(type_id) => {
+				let mut out = Vec::with_capacity(rlp.len() + 1);
+				out.push(type_id);
+				out.extend_from_slice(&rlp);
+				out
+			}
+		}
+	}
+
+	/// Decode a transaction from its EIP-2718 envelope: a leading type byte
+	/// `0x00..=0x7f` followed by a type-specific RLP payload, or a leading
+	/// byte `>= 0xc0` for a legacy RLP-list transaction.
+	pub fn decode(raw: &[u8]) -> Result<Self, DecoderError> {
+		match raw.first() {
+			None => Err(DecoderError::RlpIsTooShort),
+			Some(&first_byte) if first_byte >= 0xc0 => Self::decode_legacy(&Rlp::new(raw)),
+			Some(&type_id) if type_id <= 0x7f => Self::decode_typed(type_id, &raw[1..]),
+			Some(_) => Err(DecoderError::Custom("invalid transaction envelope")),
+		}
+	}
+
+	fn decode_legacy(d: &Rlp) -> Result<Self, DecoderError> {
+		if d.item_count()? != 9 {
+			return Err(DecoderError::RlpIncorrectListLen);
+		}
+		let unsigned = TypedTransaction::Legacy(Transaction {
+			nonce: d.val_at(0)?,
+			gas_price: d.val_at(1)?,
+			gas: d.val_at(2)?,
+			action: d.val_at(3)?,
+			value: d.val_at(4)?,
+			data: d.val_at(5)?,
+		});
+		let v = d.val_at(6)?;
+		let r = d.val_at(7)?;
+		let s = d.val_at(8)?;
+		let hash = keccak(d.as_raw());
+		Ok(UnverifiedTransaction { unsigned, v, r, s, hash })
+	}
+
+	fn decode_typed(type_id: u8, payload: &[u8]) -> Result<Self, DecoderError> {
+		let unsigned_and_sig = match type_id {
+			0x01 => Self::decode_access_list(payload)?,
+			0x02 => Self::decode_eip1559(payload)?,
+			_ => return Err(DecoderError::Custom("unknown transaction type")),
+		};
+		let (unsigned, v, r, s) = unsigned_and_sig;
+		let mut envelope = vec![type_id];
+		envelope.extend_from_slice(payload);
+		let hash = keccak(envelope);
+		Ok(UnverifiedTransaction { unsigned, v, r, s, hash })
+	}
+
+	fn decode_access_list(payload: &[u8]) -> Result<(TypedTransaction, u64, U256, U256), DecoderError> {
+		let d = Rlp::new(payload);
+		if d.item_count()? != 11 {
+			return Err(DecoderError::RlpIncorrectListLen);
+		}
+		let unsigned = TypedTransaction::AccessList(AccessListTransaction {
+			chain_id: d.val_at(0)?,
+			nonce: d.val_at(1)?,
+			gas_price: d.val_at(2)?,
+			gas: d.val_at(3)?,
+			action: d.val_at(4)?,
+			value: d.val_at(5)?,
+			data: d.val_at(6)?,
+			access_list: AccessList::decode(&d.at(7)?)?,
+		});
+		Ok((unsigned, d.val_at(8)?, d.val_at(9)?, d.val_at(10)?))
+	}
+
+	fn decode_eip1559(payload: &[u8]) -> Result<(TypedTransaction, u64, U256, U256), DecoderError> {
+		let d = Rlp::new(payload);
+		if d.item_count()? != 12 {
+			return Err(DecoderError::RlpIncorrectListLen);
+		}
+		let unsigned = TypedTransaction::Eip1559(Eip1559Transaction {
+			chain_id: d.val_at(0)?,
+			nonce: d.val_at(1)?,
+			max_priority_fee_per_gas: d.val_at(2)?,
+			max_fee_per_gas: d.val_at(3)?,
+			gas: d.val_at(4)?,
+			action: d.val_at(5)?,
+			value: d.val_at(6)?,
+			data: d.val_at(7)?,
+			access_list: AccessList::decode(&d.at(8)?)?,
+		});
+		Ok((unsigned, d.val_at(9)?, d.val_at(10)?, d.val_at(11)?))
+	}
+
+	fn rlp_append_envelope(&self, s: &mut RlpStream) {
+		match self.unsigned {
+			TypedTransaction::Legacy(ref tx) => {
+				s.begin_list(9);
+				s.append(&tx.nonce);
+				s.append(&tx.gas_price);
+				s.append(&tx.gas);
+				s.append(&tx.action);
+				s.append(&tx.value);
+				s.append(&tx.data);
+				s.append(&self.v);
+				s.append(&self.r);
+				s.append(&self.s);
+			}
+			TypedTransaction::AccessList(ref tx) => {
+				s.begin_list(11);
+				s.append(&tx.chain_id);
+				s.append(&tx.nonce);
+				s.append(&tx.gas_price);
+				s.append(&tx.gas);
+				s.append(&tx.action);
+				s.append(&tx.value);
+				s.append(&tx.data);
+				tx.access_list.rlp_append(s);
+				s.append(&self.v);
+				s.append(&self.r);
+				s.append(&self.s);
+			}
+			TypedTransaction::Eip1559(ref tx) => {
+				s.begin_list(12);
+				s.append(&tx.chain_id);
+				s.append(&tx.nonce);
+				s.append(&tx.max_priority_fee_per_gas);
+				s.append(&tx.max_fee_per_gas);
+				s.append(&tx.gas);
+				s.append(&tx.action);
+				s.append(&tx.value);
+				s.append(&tx.data);
+				tx.access_list.rlp_append(s);
+				s.append(&self.v);
+				s.append(&self.r);
+				s.append(&self.s);
+			}
+		}
+	}
+
+	/// Reference to the unsigned, type-specific payload.
+	pub fn unsigned(&self) -> &TypedTransaction { &self.unsigned }
+
+	/// Hash of the whole transaction envelope.
+	pub fn hash(&self) -> H256 { self.hash }
+
+	/// The chain ID this transaction is valid on, if it carries replay
+	/// protection.
+	pub fn chain_id(&self) -> Option<u64> {
+		match self.unsigned {
+			TypedTransaction::Legacy(_) => {
+				if self.v >= 35 {
+					Some((self.v - 35) / 2)
+				} else {
+					None
+				}
+			}
+			TypedTransaction::AccessList(ref tx) => Some(tx.chain_id),
+			TypedTransaction::Eip1559(ref tx) => Some(tx.chain_id),
+		}
+	}
+
+	/// The recovery id of the signature, in the range `0..=3`, or `4` when
+	/// `v` does not encode a valid recovery id for this transaction type.
+	fn standard_v(&self) -> u8 {
+		match self.unsigned {
+			TypedTransaction::Legacy(_) => {
+				if self.v >= 35 {
+					((self.v - 35) % 2) as u8
+				} else if self.v == 27 || self.v == 28 {
+					(self.v - 27) as u8
+				} else {
+					// Neither a bare recovery id (27/28) nor an EIP-155
+					// encoded one (>= 35): reject it explicitly rather than
+					// underflowing `v - 27` or wrapping into a fake 2/3 id.
+					// `v` comes straight from untrusted RLP, so this is the
+					// only thing standing between a malformed transaction
+					// and a panic or a recovered-garbage sender.
+					4
+				}
+			}
+			// Typed transactions carry `y_parity` directly instead of
+			// folding the chain ID into `v`; anything other than 0 or 1 is
+			// rejected the same way as a malformed legacy `v`.
+			TypedTransaction::AccessList(_) | TypedTransaction::Eip1559(_) => {
+				if self.v <= 1 { self.v as u8 } else { 4 }
+			}
+		}
+	}
+
+	fn signature(&self) -> Signature {
+		let r: H256 = From::from(self.r);
+		let s: H256 = From::from(self.s);
+		Signature::from_rsv(&r, &s, self.standard_v())
+	}
+
+	/// Recover the public key that signed this transaction.
+	pub fn recover_public(&self) -> Result<Public, Error> {
+		recover(&self.signature(), &self.unsigned.signing_hash(self.chain_id()))
+			.map_err(|_| Error::InvalidSignature("invalid signature".into()))
+	}
+}
+
+/// A `UnverifiedTransaction` with successfully recovered `sender`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SignedTransaction {
+	transaction: UnverifiedTransaction,
+	sender: Address,
+	public: Option<Public>,
+}
+
+impl Deref for SignedTransaction {
+	type Target = UnverifiedTransaction;
+	fn deref(&self) -> &UnverifiedTransaction { &self.transaction }
+}
+
+impl SignedTransaction {
+	/// Try to verify transaction and recover sender.
+	pub fn new(transaction: UnverifiedTransaction) -> Result<Self, Error> {
+		if transaction.standard_v() > 3 {
+			return Err(Error::InvalidSignature("invalid recovery id".into()));
+		}
+		let public = transaction.recover_public()?;
+		let sender = public_to_address(&public);
+		Ok(SignedTransaction { transaction, sender, public: Some(public) })
+	}
+
+	/// Returns transaction sender.
+	pub fn sender(&self) -> Address { self.sender }
+
+	/// Returns a public key of the sender.
+	pub fn public_key(&self) -> Option<Public> { self.public }
+}